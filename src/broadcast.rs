@@ -0,0 +1,176 @@
+#[cfg(test)]
+mod tests;
+
+use tokio::sync::broadcast;
+use tokio::sync::mpsc;
+
+use crate::error;
+
+/// Creates a new fan-out, bidirectional response channel.
+///
+/// ### Notes:
+/// Unlike [`crate::permanent::channel`] and [`crate::temporary::channel`],
+/// the forward channel here is a [`tokio::sync::broadcast`] channel, so a
+/// single [`Sender::send_collect`] call is delivered to *every* subscribed
+/// worker [`broadcast::Receiver`], not just one. Each worker replies on its
+/// own per-request reverse [`tokio::sync::mpsc`] channel, and
+/// [`Sender::send_collect`] aggregates the responses into a [`Vec`].
+///
+/// ### Arguments:
+/// - `buffer`: The size of the forward, broadcast channel.
+/// - `subscribers_hint`: The expected number of workers that will
+///   [`subscribe`](broadcast::Sender::subscribe) to this channel. This is
+///   used to size the reverse channel of [`Sender::send_collect`] so that
+///   replying workers never block on a full reverse channel.
+///
+/// ### A note on lag:
+/// A [`tokio::sync::broadcast`] channel drops messages for any worker that
+/// falls too far behind; its `recv` then returns
+/// [`broadcast::error::RecvError::Lagged`] instead of silently desyncing.
+/// The [`Receiver`] returned here maps that case onto
+/// [`error::Error::Lagged`] so the number of skipped messages isn't lost,
+/// instead of handing workers the raw [`broadcast::Receiver`] and leaving
+/// that translation to them.
+///
+/// ### Examples:
+/// ```rust
+/// # tokio_test::block_on(async {
+/// const BUFFER_SIZE: usize = 10;
+/// const NUM_WORKERS: usize = 3;
+///
+/// let (tx, rx) = response_channel::broadcast::channel::<u8, bool>(BUFFER_SIZE, NUM_WORKERS);
+///
+/// for _ in 0..NUM_WORKERS {
+///     let mut rx = rx.resubscribe();
+///     tokio::task::spawn(async move {
+///         let (message, reverse_tx) = rx.recv().await.unwrap().unwrap();
+///         reverse_tx.send(message >= 5).await.unwrap();
+///     });
+/// }
+///
+/// let responses = tx.send_collect(10, NUM_WORKERS).await.unwrap();
+/// assert_eq!(responses.len(), NUM_WORKERS);
+/// assert!(responses.into_iter().all(|response| response));
+/// # });
+/// ```
+pub fn channel<M, R>(
+    buffer: usize,
+    subscribers_hint: usize,
+) -> (Sender<M, R>, Receiver<M, R>)
+where
+    M: Clone,
+{
+    let (tx, rx) = broadcast::channel(buffer);
+    (
+        Sender {
+            tx,
+            reverse_buffer: subscribers_hint,
+        },
+        Receiver(rx),
+    )
+}
+
+/// The [`Sender`] type which contains the necessary information to provide a
+/// fan-out, bidirectional response channel.
+#[cfg_attr(not(release), derive(Debug))]
+pub struct Sender<M, R> {
+    tx: broadcast::Sender<(M, mpsc::Sender<R>)>,
+    reverse_buffer: usize,
+}
+
+impl<M, R> Sender<M, R>
+where
+    M: Clone,
+{
+    /// Subscribes a new worker to this broadcast channel.
+    ///
+    /// ### Notes:
+    /// This is equivalent to calling [`Receiver::resubscribe`] on the
+    /// [`Receiver`] returned alongside this [`Sender`] by [`channel`].
+    pub fn subscribe(&self) -> Receiver<M, R> {
+        Receiver(self.tx.subscribe())
+    }
+
+    /// Broadcasts the given message to every subscribed worker, then awaits
+    /// exactly `expected` responses (or until every reverse sender clone has
+    /// been dropped, whichever comes first).
+    ///
+    /// ### Arguments:
+    /// - `message`: The message that needs to be broadcast.
+    /// - `expected`: The number of responses to collect before returning.
+    ///
+    /// ### Notes:
+    /// If fewer than `expected` workers ever respond (e.g., because some
+    /// subscribers panicked or were dropped), the returned [`Vec`] simply
+    /// contains fewer than `expected` elements rather than blocking
+    /// forever.
+    pub async fn send_collect(
+        &self,
+        message: M,
+        expected: usize,
+    ) -> Result<Vec<R>, error::Error<M>> {
+        let (reverse_tx, mut reverse_rx) =
+            mpsc::channel(self.reverse_buffer.max(expected).max(1));
+        self.tx
+            .send((message, reverse_tx))
+            .map_err(|broadcast::error::SendError((m, _))| {
+                mpsc::error::SendError(m)
+            })?;
+        let mut responses = Vec::with_capacity(expected);
+        while responses.len() < expected {
+            match reverse_rx.recv().await {
+                Some(response) => responses.push(response),
+                None => break,
+            }
+        }
+        Ok(responses)
+    }
+}
+
+/// The worker-facing receiving half of a fan-out, bidirectional response
+/// channel, returned alongside [`Sender`] by [`channel`].
+///
+/// ### Notes:
+/// A thin wrapper around [`broadcast::Receiver`] whose [`Receiver::recv`]
+/// maps [`broadcast::error::RecvError::Lagged`] onto
+/// [`error::Error::Lagged`], so a worker doesn't have to perform that
+/// translation itself.
+#[cfg_attr(not(release), derive(Debug))]
+pub struct Receiver<M, R>(broadcast::Receiver<(M, mpsc::Sender<R>)>);
+
+impl<M, R> Receiver<M, R>
+where
+    M: Clone,
+{
+    /// Receives the next broadcast message, together with the reverse
+    /// sender used to reply to it.
+    ///
+    /// ### Notes:
+    /// Returns `Some(Err(error::Error::Lagged(skipped)))` instead of the
+    /// next message if this worker fell too far behind and the broadcast
+    /// channel dropped messages on its behalf; the worker may simply call
+    /// [`Receiver::recv`] again to pick up where the broadcast channel let
+    /// it resume. Returns [`None`] once the [`Sender`] side (and every
+    /// clone of it) has been dropped and no more messages will ever
+    /// arrive.
+    pub async fn recv(
+        &mut self,
+    ) -> Option<Result<(M, mpsc::Sender<R>), error::Error<M>>> {
+        match self.0.recv().await {
+            Ok(pair) => Some(Ok(pair)),
+            Err(broadcast::error::RecvError::Lagged(skipped)) => {
+                Some(Err(error::Error::Lagged(skipped)))
+            },
+            Err(broadcast::error::RecvError::Closed) => None,
+        }
+    }
+
+    /// Creates a new handle to the same underlying broadcast channel, an
+    /// independent reader starting from the current point.
+    ///
+    /// ### Notes:
+    /// This is equivalent to [`broadcast::Receiver::resubscribe`].
+    pub fn resubscribe(&self) -> Self {
+        Self(self.0.resubscribe())
+    }
+}
@@ -0,0 +1,62 @@
+use super::channel;
+
+#[tokio::test]
+async fn send_collect_aggregates_every_worker_response() {
+    const NUM_WORKERS: usize = 3;
+
+    let (tx, rx) = channel::<u8, bool>(10, NUM_WORKERS);
+
+    for _ in 0..NUM_WORKERS {
+        let mut rx = rx.resubscribe();
+        tokio::task::spawn(async move {
+            let (message, reverse_tx) = rx.recv().await.unwrap().unwrap();
+            reverse_tx.send(message >= 5).await.unwrap();
+        });
+    }
+    drop(rx);
+
+    let responses = tx.send_collect(10, NUM_WORKERS).await.unwrap();
+    assert_eq!(responses.len(), NUM_WORKERS);
+    assert!(responses.into_iter().all(|response| response));
+}
+
+#[tokio::test]
+async fn send_collect_returns_early_if_a_worker_declines_to_respond() {
+    let (tx, rx) = channel::<u8, bool>(10, 2);
+
+    // this worker receives the message but drops its reverse sender
+    // without ever responding.
+    tokio::task::spawn(async move {
+        let mut rx = rx;
+        let (_message, reverse_tx) = rx.recv().await.unwrap().unwrap();
+        drop(reverse_tx);
+    });
+
+    // the reverse channel closes once every clone (the worker's and the
+    // one held in the broadcast buffer until every subscriber has read
+    // the message) is dropped, so we get back fewer responses than
+    // `expected` instead of blocking forever.
+    let responses = tx.send_collect(10, 2).await.unwrap();
+    assert!(responses.is_empty());
+}
+
+#[tokio::test]
+async fn lagged_worker_gets_a_lagged_error_instead_of_desyncing() {
+    use crate::error::Error;
+
+    let (tx, mut rx) = channel::<u8, bool>(1, 1);
+
+    // the broadcast buffer only holds 1 message, so the second and third
+    // sends push the first one out before this worker ever reads it.
+    tx.send_collect(1, 0).await.unwrap();
+    tx.send_collect(2, 0).await.unwrap();
+    tx.send_collect(3, 0).await.unwrap();
+
+    let err = rx.recv().await.unwrap().unwrap_err();
+    assert_eq!(err, Error::Lagged(2));
+
+    // the worker can resume after the lag; the next `recv` picks up with
+    // the most recent message that's still buffered.
+    let (message, _reverse_tx) = rx.recv().await.unwrap().unwrap();
+    assert_eq!(message, 3);
+}
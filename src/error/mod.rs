@@ -3,12 +3,16 @@ mod tests;
 
 use derive_more::Display;
 use tokio::sync::mpsc::error::SendError;
+use tokio::sync::mpsc::error::TrySendError;
 use tokio::sync::oneshot::error::RecvError;
+use tokio::time::error::Elapsed;
 
-#[derive(Display)]
+#[derive(Display, Debug)]
 /// The error type.
 ///
-/// Since only [`tokio::sync::mpsc::error::SendError`] and
+/// Since, besides [`Error::Lagged`], [`Error::Elapsed`] and
+/// [`Error::Transport`], only [`tokio::sync::mpsc::error::SendError`],
+/// [`tokio::sync::mpsc::error::TrySendError`], and
 /// [`tokio::sync::oneshot::error::RecvError`] are the only possible errors,
 /// [`Error`] just wraps these in an enum.
 pub enum Error<M> {
@@ -18,11 +22,43 @@ pub enum Error<M> {
     #[display(fmt = "(mpsc) Send Error: {}", _0)]
     SendError(SendError<M>),
 
+    /// The [`tokio::sync::mpsc::error::TrySendError`] variant.
+    ///
+    /// Occurs if the forward channel is full or closed when attempting a
+    /// non-blocking send.
+    #[display(fmt = "(mpsc) Try Send Error: {}", _0)]
+    TrySendError(TrySendError<M>),
+
     /// The [`tokio::sync::oneshot::error::RecvError`] variant.
     ///
     /// Occurs if an error in receiving the response occurs.
     #[display(fmt = "(oneshot) Receive Error: {}", _0)]
     RecvError(RecvError),
+
+    /// A lagged [`broadcast`](crate::broadcast) receiver skipped this many
+    /// messages.
+    ///
+    /// Occurs when a worker's [`tokio::sync::broadcast::Receiver`] falls too
+    /// far behind the broadcast channel and misses messages; see
+    /// [`tokio::sync::broadcast::error::RecvError::Lagged`].
+    #[display(fmt = "(broadcast) Lagged Error: skipped {} message(s)", _0)]
+    Lagged(u64),
+
+    /// The [`tokio::time::error::Elapsed`] variant.
+    ///
+    /// Occurs if a timeout-bounded wait for a response elapses before a
+    /// response arrives.
+    #[display(fmt = "(time) Elapsed Error: {}", _0)]
+    Elapsed(Elapsed),
+
+    /// The [`crate::transport::TransportError`] variant.
+    ///
+    /// Occurs when the stream behind a [`crate::transport::Sender`] closes
+    /// (or fails with an I/O error) with a request still in flight; the
+    /// [`crate::transport::Sender`] then reports
+    /// [`crate::transport::TransportError::Closed`].
+    #[display(fmt = "(transport) Error: {}", _0)]
+    Transport(crate::transport::TransportError),
 }
 
 impl<M> PartialEq for Error<M>
@@ -35,9 +71,22 @@ where
                 Self::SendError(SendError(err)),
                 Self::SendError(SendError(other_err)),
             ) => err.eq(other_err),
+            (
+                Self::TrySendError(err),
+                Self::TrySendError(other_err),
+            ) => err.eq(other_err),
             (Self::RecvError(err), Self::RecvError(other_err)) => {
                 err.eq(other_err)
             },
+            (Self::Lagged(skipped), Self::Lagged(other_skipped)) => {
+                skipped.eq(other_skipped)
+            },
+            (Self::Elapsed(err), Self::Elapsed(other_err)) => {
+                err.eq(other_err)
+            },
+            (Self::Transport(err), Self::Transport(other_err)) => {
+                err.eq(other_err)
+            },
             _ => false,
         }
     }
@@ -55,16 +104,28 @@ impl<M> From<SendError<M>> for Error<M> {
     }
 }
 
+impl<M> From<TrySendError<M>> for Error<M> {
+    fn from(err: TrySendError<M>) -> Self {
+        Self::TrySendError(err)
+    }
+}
+
 impl<M> From<RecvError> for Error<M> {
     fn from(err: RecvError) -> Self {
         Self::RecvError(err)
     }
 }
 
-impl<M> std::error::Error for Error<M> {}
+impl<M> From<Elapsed> for Error<M> {
+    fn from(err: Elapsed) -> Self {
+        Self::Elapsed(err)
+    }
+}
 
-impl<M> std::fmt::Debug for Error<M> {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{:?}", self)
+impl<M> From<crate::transport::TransportError> for Error<M> {
+    fn from(err: crate::transport::TransportError) -> Self {
+        Self::Transport(err)
     }
 }
+
+impl<M> std::error::Error for Error<M> where M: std::fmt::Debug {}
@@ -20,3 +20,34 @@ fn send_error_eq() {
     assert_eq!(b, c);
     assert_eq!(a, c);
 }
+
+#[test]
+fn try_send_error_eq() {
+    let a = Error::TrySendError(mpsc::error::TrySendError::Full(10));
+    let b = Error::TrySendError(mpsc::error::TrySendError::Full(10));
+    let c: Error<u8> = Error::TrySendError(mpsc::error::TrySendError::Closed(10));
+
+    assert_eq!(a, b);
+    assert_ne!(a, c);
+}
+
+#[tokio::test]
+async fn elapsed_error_eq() {
+    let timeout_a = tokio::time::timeout(
+        std::time::Duration::from_millis(0),
+        std::future::pending::<()>(),
+    )
+    .await
+    .unwrap_err();
+    let timeout_b = tokio::time::timeout(
+        std::time::Duration::from_millis(0),
+        std::future::pending::<()>(),
+    )
+    .await
+    .unwrap_err();
+
+    let a: Error<u8> = Error::Elapsed(timeout_a);
+    let b: Error<u8> = Error::Elapsed(timeout_b);
+
+    assert_eq!(a, b);
+}
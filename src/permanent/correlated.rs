@@ -0,0 +1,212 @@
+#[cfg(test)]
+mod tests;
+
+use std::collections::HashMap;
+use std::collections::HashSet;
+use std::collections::VecDeque;
+use std::ops::Deref;
+use std::sync::atomic::AtomicU64;
+use std::sync::atomic::Ordering;
+use std::sync::Arc;
+
+use tokio::sync::mpsc;
+
+use crate::error;
+
+/// Creates a new permanent, bidirectional, *correlated* response channel.
+///
+/// ### Notes:
+/// This is identical to [`crate::permanent::channel`], except that every
+/// forwarded message is tagged with a monotonically increasing request id.
+/// This allows [`Sender::send_await_automatic`] to match a response back to
+/// the exact message that produced it, even if the receiver answers out of
+/// order (e.g., because it spawns a task per message). If ordering is
+/// guaranteed on the receiving end, prefer the plain, uncorrelated
+/// [`crate::permanent::channel`] instead.
+///
+/// ### Arguments:
+/// - `buffer`: The size of the forward channel.
+/// - `reverse_buffer`: The size of the reverse channel. If this is [`None`],
+///   `buffer` will be used.
+pub fn channel<M, R>(
+    buffer: usize,
+    reverse_buffer: Option<usize>,
+) -> (
+    Sender<M, R>,
+    mpsc::Receiver<(M, u64, mpsc::Sender<(u64, R)>)>,
+) {
+    let (tx, rx) = mpsc::channel(buffer);
+    let (reverse_tx, reverse_rx) =
+        mpsc::channel(reverse_buffer.unwrap_or(buffer));
+    (
+        Sender {
+            tx,
+            reverse_tx,
+            reverse_rx,
+            next_id: Arc::new(AtomicU64::new(0)),
+            pending: HashMap::new(),
+            returned: ReturnedIds::new(buffer),
+        },
+        rx,
+    )
+}
+
+/// A FIFO-bounded set of already-returned ids.
+///
+/// ### Notes:
+/// A plain, ever-growing `HashSet` here would leak memory for the
+/// lifetime of a long-lived [`Sender`], since an entry is added on every
+/// successful [`Sender::recv`] and never removed. Capping it at `capacity`
+/// and evicting the oldest id once that's exceeded bounds the memory use;
+/// the tradeoff is that a duplicate response arriving for an id evicted
+/// long ago is no longer recognized as such and is buffered into
+/// `pending` like a genuine out-of-order response, instead of being
+/// dropped. `capacity` is sized off the forward channel's own buffer, so
+/// this window comfortably covers the requests actually in flight.
+#[cfg_attr(not(release), derive(Debug))]
+struct ReturnedIds {
+    capacity: usize,
+    order: VecDeque<u64>,
+    set: HashSet<u64>,
+}
+
+impl ReturnedIds {
+    fn new(capacity: usize) -> Self {
+        Self {
+            capacity: capacity.max(1),
+            order: VecDeque::new(),
+            set: HashSet::new(),
+        }
+    }
+
+    fn contains(&self, id: &u64) -> bool {
+        self.set.contains(id)
+    }
+
+    fn insert(&mut self, id: u64) {
+        if !self.set.insert(id) {
+            return;
+        }
+        self.order.push_back(id);
+        if self.order.len() > self.capacity {
+            if let Some(evicted) = self.order.pop_front() {
+                self.set.remove(&evicted);
+            }
+        }
+    }
+}
+
+/// The [`Sender`] type which contains the necessary information to provide a
+/// correlated, permanent, bidirectional response channel.
+#[cfg_attr(not(release), derive(Debug))]
+pub struct Sender<M, R> {
+    pub(crate) tx: mpsc::Sender<(M, u64, mpsc::Sender<(u64, R)>)>,
+    pub(crate) reverse_tx: mpsc::Sender<(u64, R)>,
+    pub(crate) reverse_rx: mpsc::Receiver<(u64, R)>,
+    pub(crate) next_id: Arc<AtomicU64>,
+    pub(crate) pending: HashMap<u64, R>,
+    /// Ids whose response has already been returned by [`Sender::recv`],
+    /// so a stray duplicate answer for one of them is dropped instead of
+    /// buffered forever. Bounded (see [`ReturnedIds`]) so this doesn't
+    /// grow for the lifetime of a long-lived [`Sender`].
+    returned: ReturnedIds,
+}
+
+impl<M, R> Sender<M, R> {
+    fn next_id(&self) -> u64 {
+        self.next_id.fetch_add(1, Ordering::Relaxed)
+    }
+
+    /// Sends the given message to the receiver, returning the id it was
+    /// tagged with.
+    ///
+    /// ### Notes:
+    /// This function does *not* try to receive the response! The user must
+    /// do this explicitly (matching against the returned id) if they are
+    /// required to read the response.
+    pub async fn send_await(
+        &self,
+        message: M,
+    ) -> Result<u64, error::Error<M>> {
+        let id = self.next_id();
+        self.tx
+            .send((message, id, self.reverse_tx.clone()))
+            .await
+            .map_err(|mpsc::error::SendError((m, _, _))| {
+                mpsc::error::SendError(m)
+            })?;
+        Ok(id)
+    }
+
+    /// Receives the response tagged with the given id, regardless of what
+    /// order responses come back in.
+    ///
+    /// ### Notes:
+    /// Responses that arrive for other, still-outstanding ids are buffered
+    /// in an internal map rather than discarded. This map is consulted (and
+    /// drained) before awaiting the reverse channel, so a
+    /// previously-buffered answer is returned without blocking. A response
+    /// for an id that has already been returned (e.g., a buggy or
+    /// adversarial worker answering the same id twice) is dropped instead
+    /// of buffered, so it can't accumulate in `pending` forever. That
+    /// check is itself bounded (see [`ReturnedIds`]), so a duplicate
+    /// arriving long after its id was returned may instead be buffered
+    /// into `pending` like a genuine out-of-order response.
+    pub async fn recv(&mut self, id: u64) -> Option<R> {
+        if let Some(response) = self.pending.remove(&id) {
+            self.returned.insert(id);
+            return Some(response);
+        }
+        loop {
+            match self.reverse_rx.recv().await {
+                Some((received_id, response)) if received_id == id => {
+                    self.returned.insert(id);
+                    return Some(response);
+                },
+                Some((received_id, response)) => {
+                    if !self.returned.contains(&received_id) {
+                        self.pending.insert(received_id, response);
+                    }
+                },
+                None => return None,
+            }
+        }
+    }
+
+    /// Sends the given message and awaits *its* matching response,
+    /// regardless of what order responses come back in.
+    ///
+    /// ### Notes:
+    /// This is equivalent to calling [`Sender::send_await`] followed
+    /// immediately by [`Sender::recv`] with the returned id.
+    pub async fn send_await_automatic(
+        &mut self,
+        message: M,
+    ) -> Result<Option<R>, error::Error<M>> {
+        let id = self.send_await(message).await?;
+        Ok(self.recv(id).await)
+    }
+}
+
+impl<M, R> Clone for Sender<M, R> {
+    fn clone(&self) -> Self {
+        let reverse_buffer = self.reverse_tx.max_capacity();
+        let (reverse_tx, reverse_rx) = mpsc::channel(reverse_buffer);
+        Self {
+            tx: self.tx.clone(),
+            reverse_tx,
+            reverse_rx,
+            next_id: Arc::clone(&self.next_id),
+            pending: HashMap::new(),
+            returned: ReturnedIds::new(self.returned.capacity),
+        }
+    }
+}
+
+impl<M, R> Deref for Sender<M, R> {
+    type Target = mpsc::Sender<(M, u64, mpsc::Sender<(u64, R)>)>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.tx
+    }
+}
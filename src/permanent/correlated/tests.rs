@@ -0,0 +1,47 @@
+use super::channel;
+
+#[tokio::test]
+async fn out_of_order_responses_are_matched_by_id() {
+    let (mut tx, mut rx) = channel::<u8, u8>(10, None);
+
+    let id0 = tx.send_await(10).await.unwrap();
+    let id1 = tx.send_await(11).await.unwrap();
+
+    let (_, received_id0, reverse_tx0) = rx.recv().await.unwrap();
+    let (_, received_id1, reverse_tx1) = rx.recv().await.unwrap();
+    assert_eq!(received_id0, id0);
+    assert_eq!(received_id1, id1);
+
+    // answer out of order, as if the receiver spawned a task per message.
+    reverse_tx1.send((id1, 1)).await.unwrap();
+    reverse_tx0.send((id0, 0)).await.unwrap();
+
+    // `recv(id0)` must not be confused by the already-arrived response for
+    // `id1`; it should buffer it and keep waiting for `id0`.
+    assert_eq!(tx.recv(id0).await, Some(0));
+    // the buffered response for `id1` is then returned without blocking.
+    assert_eq!(tx.recv(id1).await, Some(1));
+}
+
+#[tokio::test]
+async fn duplicate_response_for_an_already_returned_id_is_dropped() {
+    let (mut tx, mut rx) = channel::<u8, u8>(10, None);
+
+    let id = tx.send_await(10).await.unwrap();
+    let (_, received_id, reverse_tx) = rx.recv().await.unwrap();
+    assert_eq!(received_id, id);
+
+    reverse_tx.send((id, 1)).await.unwrap();
+    assert_eq!(tx.recv(id).await, Some(1));
+
+    // a buggy/adversarial worker answers the same id a second time; it
+    // must not be buffered in `pending` forever.
+    reverse_tx.send((id, 2)).await.unwrap();
+    drop(reverse_tx);
+
+    let next_id = tx.send_await(11).await.unwrap();
+    let (_, received_next_id, reverse_tx) = rx.recv().await.unwrap();
+    reverse_tx.send((received_next_id, 12)).await.unwrap();
+    assert_eq!(tx.recv(next_id).await, Some(12));
+    assert!(tx.pending.is_empty());
+}
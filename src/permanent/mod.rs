@@ -1,6 +1,11 @@
 #[cfg(test)]
 mod tests;
 
+/// A correlated flavour of the permanent response channel, for when a
+/// cloned [`Sender`] may have multiple requests in flight at once and the
+/// receiver may answer them out of order.
+pub mod correlated;
+
 use std::ops::Deref;
 
 use tokio::sync::mpsc;
@@ -173,6 +178,91 @@ impl<M, R> Sender<M, R> {
     pub async fn recv(&mut self) -> Option<R> {
         self.reverse_rx.recv().await
     }
+
+    /// Attempts to send the given message to the receiver without awaiting.
+    ///
+    /// ### Arguments:
+    /// - `message`: The message that needs to be sent.
+    ///
+    /// ### Notes:
+    /// Unlike [`Sender::send_await`], this method never suspends. If the
+    /// forward channel is full (or has been closed), [`error::Error::TrySendError`]
+    /// is returned immediately so the caller can decide how to handle
+    /// backpressure instead of blocking on it.
+    ///
+    /// ### Example:
+    /// ```rust
+    /// # let (tx, rx) = response_channel::permanent::channel::<u8, bool>(1, None);
+    /// tx.try_send_await(10).unwrap();
+    /// # drop(rx);
+    /// ```
+    pub fn try_send_await(&self, message: M) -> Result<(), error::Error<M>> {
+        self.tx
+            .try_send((message, self.reverse_tx.clone()))
+            .map_err(|err| match err {
+                mpsc::error::TrySendError::Full((m, _)) => {
+                    mpsc::error::TrySendError::Full(m)
+                },
+                mpsc::error::TrySendError::Closed((m, _)) => {
+                    mpsc::error::TrySendError::Closed(m)
+                },
+            })?;
+        Ok(())
+    }
+
+    /// Reserves a slot in the forward channel ahead of time.
+    ///
+    /// ### Notes:
+    /// The returned [`SendPermit`] has already claimed its spot in the
+    /// forward channel (along with a freshly-cloned reverse sender), so
+    /// calling [`SendPermit::send`] is guaranteed not to block.
+    ///
+    /// ### Example:
+    /// ```rust
+    /// # tokio_test::block_on(async {
+    /// # let (tx, rx) = response_channel::permanent::channel::<u8, bool>(1, None);
+    /// let permit = tx.reserve_await().await.unwrap();
+    /// permit.send(10);
+    /// # drop(rx);
+    /// # });
+    /// ```
+    pub async fn reserve_await(
+        &self,
+    ) -> Result<SendPermit<M, R>, mpsc::error::SendError<()>> {
+        let permit = self.tx.clone().reserve_owned().await?;
+        Ok(SendPermit {
+            permit,
+            reverse_tx: self.reverse_tx.clone(),
+        })
+    }
+
+    /// Like [`Sender::recv`], but fails with [`error::Error::Elapsed`] if no
+    /// response arrives within `duration`.
+    pub async fn recv_timeout(
+        &mut self,
+        duration: std::time::Duration,
+    ) -> Result<Option<R>, error::Error<M>> {
+        let response =
+            tokio::time::timeout(duration, self.reverse_rx.recv()).await?;
+        Ok(response)
+    }
+
+    /// Like [`Sender::send_await_automatic`], but fails with
+    /// [`error::Error::Elapsed`] if no response arrives within `duration`.
+    ///
+    /// ### Notes:
+    /// If this times out, the worker's response (if it arrives later) is
+    /// *not* lost: it stays queued in the reverse channel and will be
+    /// returned by the next call to [`Sender::recv`] or
+    /// [`Sender::recv_timeout`].
+    pub async fn send_await_automatic_timeout(
+        &mut self,
+        message: M,
+        duration: std::time::Duration,
+    ) -> Result<Option<R>, error::Error<M>> {
+        self.send_await(message).await?;
+        self.recv_timeout(duration).await
+    }
 }
 
 impl<M, R> Deref for Sender<M, R> {
@@ -182,3 +272,21 @@ impl<M, R> Deref for Sender<M, R> {
         &self.tx
     }
 }
+
+/// A permit which has already reserved a slot in the forward channel.
+///
+/// Obtained via [`Sender::reserve_await`]. Since the slot (and the reverse
+/// sender) have already been claimed, [`SendPermit::send`] is guaranteed
+/// not to block.
+#[cfg_attr(not(release), derive(Debug))]
+pub struct SendPermit<M, R> {
+    permit: mpsc::OwnedPermit<(M, mpsc::Sender<R>)>,
+    reverse_tx: mpsc::Sender<R>,
+}
+
+impl<M, R> SendPermit<M, R> {
+    /// Sends the given message using the already-reserved slot.
+    pub fn send(self, message: M) {
+        self.permit.send((message, self.reverse_tx));
+    }
+}
@@ -0,0 +1,134 @@
+#[cfg(test)]
+mod tests;
+
+use std::time::Duration;
+
+use tokio::sync::mpsc;
+use tokio::task::JoinHandle;
+
+use crate::error;
+use crate::permanent;
+
+/// Configuration for how a [`Sender`] retries a reconnect after the
+/// worker-owned end of the forward channel closes.
+#[derive(Clone, Copy, Debug)]
+pub struct RetryPolicy {
+    /// The maximum number of reconnect attempts before giving up and
+    /// returning the underlying error.
+    pub max_attempts: usize,
+    /// How long to wait between successive reconnect attempts.
+    pub backoff: Duration,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_attempts: 3,
+            backoff: Duration::from_millis(100),
+        }
+    }
+}
+
+type Factory<M, R> = Box<
+    dyn FnMut() -> (mpsc::Sender<(M, mpsc::Sender<R>)>, JoinHandle<()>)
+        + Send,
+>;
+
+/// A self-reconnecting wrapper around a [`permanent::Sender`].
+///
+/// ### Notes:
+/// When the worker task owning the receiving end of the forward channel
+/// panics or exits, [`permanent::Sender::send_await`] starts failing
+/// permanently with [`error::Error::SendError`], forcing callers to tear
+/// the whole channel down. This wrapper instead catches that failure and
+/// re-runs the supplied factory to rebuild the forward channel (and
+/// respawn the worker), up to `policy.max_attempts` times with
+/// `policy.backoff` between attempts, before finally surfacing the
+/// underlying [`error::Error`]. Callers of [`Sender::send_await_automatic`]
+/// see a transient reconnect rather than a hard failure.
+pub struct Sender<M, R> {
+    inner: permanent::Sender<M, R>,
+    factory: Factory<M, R>,
+    policy: RetryPolicy,
+    worker: JoinHandle<()>,
+}
+
+impl<M, R> Sender<M, R> {
+    /// Builds a new supervised [`Sender`] from a factory.
+    ///
+    /// ### Arguments:
+    /// - `factory`: rebuilds the forward channel and respawns the worker
+    ///   owning its receiving end. Called once up-front, and again every
+    ///   time a reconnect is needed.
+    /// - `reverse_buffer`: the size of the reverse channel. If this is
+    ///   [`None`], the forward channel's capacity (as reported by the first
+    ///   call to `factory`) is used.
+    /// - `policy`: the retry/backoff policy applied on reconnect.
+    pub fn new<F>(
+        mut factory: F,
+        reverse_buffer: Option<usize>,
+        policy: RetryPolicy,
+    ) -> Self
+    where
+        F: FnMut() -> (mpsc::Sender<(M, mpsc::Sender<R>)>, JoinHandle<()>)
+            + Send
+            + 'static,
+    {
+        let (tx, worker) = factory();
+        let reverse_buffer =
+            reverse_buffer.unwrap_or_else(|| tx.max_capacity());
+        let (reverse_tx, reverse_rx) = mpsc::channel(reverse_buffer);
+        Self {
+            inner: permanent::Sender {
+                tx,
+                reverse_tx,
+                reverse_rx,
+            },
+            factory: Box::new(factory),
+            policy,
+            worker,
+        }
+    }
+
+    /// The [`JoinHandle`] of the worker task currently backing this
+    /// [`Sender`] (replaced on every reconnect).
+    pub fn worker(&self) -> &JoinHandle<()> {
+        &self.worker
+    }
+
+    async fn reconnect(&mut self) {
+        let (tx, worker) = (self.factory)();
+        self.inner.tx = tx;
+        self.worker = worker;
+    }
+
+    /// Sends the given message and awaits its response, transparently
+    /// reconnecting (per this [`Sender`]'s [`RetryPolicy`]) if the forward
+    /// channel has closed.
+    ///
+    /// ### Notes:
+    /// Only a closed forward channel ([`error::Error::SendError`]) triggers
+    /// a reconnect attempt; any other error is returned immediately. Once
+    /// `policy.max_attempts` reconnects have been tried and exhausted, the
+    /// final underlying [`error::Error`] is returned.
+    pub async fn send_await_automatic(
+        &mut self,
+        mut message: M,
+    ) -> Result<Option<R>, error::Error<M>> {
+        let mut attempt = 0;
+        loop {
+            match self.inner.send_await_automatic(message).await {
+                Ok(response) => return Ok(response),
+                Err(error::Error::SendError(mpsc::error::SendError(m)))
+                    if attempt < self.policy.max_attempts =>
+                {
+                    attempt += 1;
+                    tokio::time::sleep(self.policy.backoff).await;
+                    self.reconnect().await;
+                    message = m;
+                },
+                Err(err) => return Err(err),
+            }
+        }
+    }
+}
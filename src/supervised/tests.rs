@@ -0,0 +1,70 @@
+use std::sync::atomic::AtomicUsize;
+use std::sync::atomic::Ordering;
+use std::sync::Arc;
+use std::time::Duration;
+
+use tokio::sync::mpsc;
+
+use super::RetryPolicy;
+use super::Sender;
+
+#[tokio::test]
+async fn reconnects_after_worker_dies() {
+    let calls = Arc::new(AtomicUsize::new(0));
+    let calls_factory = Arc::clone(&calls);
+
+    let factory = move || {
+        let call = calls_factory.fetch_add(1, Ordering::SeqCst);
+        let (tx, rx) = mpsc::channel::<(u8, mpsc::Sender<u8>)>(10);
+        if call == 0 {
+            // the "worker" for this attempt is already dead: its receiver
+            // is dropped before the sender ever gets to use it.
+            drop(rx);
+        } else {
+            tokio::task::spawn(async move {
+                let mut rx = rx;
+                while let Some((message, reverse_tx)) = rx.recv().await {
+                    reverse_tx.send(message + 1).await.unwrap();
+                }
+            });
+        }
+        let worker = tokio::task::spawn(async {});
+        (tx, worker)
+    };
+
+    let mut sender = Sender::new(
+        factory,
+        None,
+        RetryPolicy {
+            max_attempts: 2,
+            backoff: Duration::from_millis(1),
+        },
+    );
+
+    let response = sender.send_await_automatic(10).await.unwrap().unwrap();
+    assert_eq!(response, 11);
+    assert_eq!(calls.load(Ordering::SeqCst), 2);
+}
+
+#[tokio::test]
+async fn gives_up_after_exhausting_retry_budget() {
+    let factory = || {
+        let (tx, rx) = mpsc::channel::<(u8, mpsc::Sender<u8>)>(10);
+        // every attempt's worker is already dead.
+        drop(rx);
+        let worker = tokio::task::spawn(async {});
+        (tx, worker)
+    };
+
+    let mut sender = Sender::new(
+        factory,
+        None,
+        RetryPolicy {
+            max_attempts: 2,
+            backoff: Duration::from_millis(1),
+        },
+    );
+
+    let err = sender.send_await_automatic(10).await.unwrap_err();
+    assert!(matches!(err, crate::error::Error::SendError(_)));
+}
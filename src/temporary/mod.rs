@@ -80,6 +80,62 @@ impl<M, R> Sender<M, R> {
         let response = self.send_await(message).await?.await?;
         Ok(response)
     }
+
+    /// Attempts to send the given message to the receiver without awaiting.
+    ///
+    /// ### Notes:
+    /// Unlike [`Sender::send_await`], this method never suspends. If the
+    /// forward channel is full (or has been closed),
+    /// [`mpsc::error::TrySendError`] is returned immediately.
+    pub fn try_send_await(
+        &self,
+        message: M,
+    ) -> Result<oneshot::Receiver<R>, mpsc::error::TrySendError<M>> {
+        let (tx, rx) = oneshot::channel::<R>();
+        self.0.try_send((message, tx)).map(|()| rx).map_err(|err| {
+            match err {
+                mpsc::error::TrySendError::Full((m, _)) => {
+                    mpsc::error::TrySendError::Full(m)
+                },
+                mpsc::error::TrySendError::Closed((m, _)) => {
+                    mpsc::error::TrySendError::Closed(m)
+                },
+            }
+        })
+    }
+
+    /// Reserves a slot in the forward channel ahead of time.
+    ///
+    /// ### Notes:
+    /// The returned [`SendPermit`] has already claimed its spot in the
+    /// forward channel, so calling [`SendPermit::send`] is guaranteed not
+    /// to block.
+    pub async fn reserve_await(
+        &self,
+    ) -> Result<SendPermit<M, R>, mpsc::error::SendError<()>> {
+        let permit = self.0.clone().reserve_owned().await?;
+        Ok(SendPermit { permit })
+    }
+
+    /// Like [`Sender::send_await_automatic`], but fails with
+    /// [`Error::Elapsed`] if no response arrives within `duration`.
+    ///
+    /// ### Notes:
+    /// On timeout, the [`oneshot::Receiver`] is simply dropped, which
+    /// cancels it; there is no persistent reverse channel to drain
+    /// afterwards (unlike [`crate::permanent::Sender::recv_timeout`]), so no
+    /// separate `recv_timeout` is provided here. If you already hold the
+    /// [`oneshot::Receiver`] returned by [`Sender::send_await`], you can
+    /// time it out yourself with [`tokio::time::timeout`].
+    pub async fn send_await_automatic_timeout(
+        &self,
+        message: M,
+        duration: std::time::Duration,
+    ) -> Result<R, Error<M>> {
+        let rx = self.send_await(message).await?;
+        let response = tokio::time::timeout(duration, rx).await??;
+        Ok(response)
+    }
 }
 
 impl<M, R> Deref for Sender<M, R> {
@@ -89,3 +145,22 @@ impl<M, R> Deref for Sender<M, R> {
         &self.0
     }
 }
+
+/// A permit which has already reserved a slot in the forward channel.
+///
+/// Obtained via [`Sender::reserve_await`]. Since the slot has already been
+/// claimed, [`SendPermit::send`] is guaranteed not to block.
+#[cfg_attr(not(release), derive(Debug))]
+pub struct SendPermit<M, R> {
+    permit: mpsc::OwnedPermit<(M, oneshot::Sender<R>)>,
+}
+
+impl<M, R> SendPermit<M, R> {
+    /// Sends the given message using the already-reserved slot, returning
+    /// the [`oneshot::Receiver`] the response will arrive on.
+    pub fn send(self, message: M) -> oneshot::Receiver<R> {
+        let (response_tx, response_rx) = oneshot::channel();
+        self.permit.send((message, response_tx));
+        response_rx
+    }
+}
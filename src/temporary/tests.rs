@@ -0,0 +1,15 @@
+use super::channel;
+
+#[tokio::test]
+async fn send_and_recv_round_trip() {
+    let (tx, mut rx) = channel::<u8, u8>(10);
+
+    tokio::task::spawn(async move {
+        while let Some((message, reverse_tx)) = rx.recv().await {
+            reverse_tx.send(message + 1).unwrap();
+        }
+    });
+
+    let response = tx.send_await_automatic(10).await.unwrap();
+    assert_eq!(response, 11);
+}
@@ -0,0 +1,35 @@
+use tokio::sync::mpsc;
+
+use super::channel;
+use super::error;
+
+#[tokio::test]
+async fn try_send_await_fails_with_full_when_the_forward_channel_is_full() {
+    let (tx, _rx) = channel::<u8, u8>(1, None);
+
+    // fills the one forward slot; nothing drains the channel, so this
+    // message just sits there.
+    tx.try_send_await(1).unwrap();
+
+    let err = tx.try_send_await(2).unwrap_err();
+    assert!(matches!(
+        err,
+        error::Error::TrySendError(mpsc::error::TrySendError::Full(2)),
+    ));
+}
+
+#[tokio::test]
+async fn reserve_await_resolves_once_a_slot_frees_up() {
+    let (tx, mut rx) = channel::<u8, u8>(1, None);
+    tx.try_send_await(1).unwrap();
+
+    // the forward channel is full, so `reserve_await` can't resolve until
+    // the queued message below is drained and frees up a slot.
+    let (permit, first) = tokio::join!(tx.reserve_await(), rx.recv());
+    assert_eq!(first.map(|(message, _)| message), Some(1));
+
+    // the permit already holds its slot, so this is guaranteed not to block.
+    permit.unwrap().send(2);
+    let (second, _reverse_tx) = rx.recv().await.unwrap();
+    assert_eq!(second, 2);
+}
@@ -0,0 +1,411 @@
+#[cfg(test)]
+mod tests;
+
+use std::collections::HashMap;
+use std::io;
+use std::sync::atomic::AtomicU64;
+use std::sync::atomic::Ordering;
+use std::sync::Arc;
+
+use bytes::Bytes;
+use derive_more::Display;
+use tokio::io::AsyncRead;
+use tokio::io::AsyncReadExt;
+use tokio::io::AsyncWrite;
+use tokio::io::AsyncWriteExt;
+use tokio::sync::mpsc;
+use tokio::sync::oneshot;
+use tokio::sync::Mutex;
+
+use crate::error;
+
+/// The magic bytes identifying a `response_channel` transport handshake.
+const MAGIC: [u8; 4] = *b"RSPC";
+
+/// The protocol version spoken by this build of the crate.
+///
+/// A peer handshaking with a different version is rejected before any
+/// request/response frames are exchanged, so incompatible peers fail fast
+/// instead of desyncing on the framing below.
+const PROTOCOL_VERSION: u8 = 1;
+
+/// The largest payload, in bytes, a single frame is allowed to declare.
+///
+/// Every frame is prefixed with a `u32` length that is attacker-controlled
+/// (it comes straight off the wire, before the payload itself has even
+/// been read). This bounds how much we'll allocate on its word alone, so a
+/// malformed or hostile peer can't force a multi-gigabyte allocation with
+/// a single frame.
+const MAX_FRAME_LEN: u32 = 16 * 1024 * 1024;
+
+/// Encodes values of type `T` into their wire representation.
+///
+/// Implement this (alongside [`Decode`]) to choose your own wire format
+/// (JSON, bincode, etc).
+pub trait Encode<T> {
+    /// Encodes a value into its wire representation.
+    fn encode(&self, value: &T) -> Bytes;
+}
+
+/// Decodes values of type `T` from their wire representation.
+///
+/// Implement this (alongside [`Encode`]) to choose your own wire format
+/// (JSON, bincode, etc).
+pub trait Decode<T> {
+    /// Decodes a value from its wire representation.
+    fn decode(&self, bytes: &[u8]) -> T;
+}
+
+/// A codec able to encode outgoing messages and decode incoming responses,
+/// for use by a [`Sender`].
+///
+/// Blanket-implemented for anything that implements [`Encode<M>`] and
+/// [`Decode<R>`]; a [`Receiver`] needs the opposite pairing
+/// (`Encode<R> + Decode<M>`), since it decodes messages and encodes
+/// responses.
+pub trait Codec<M, R>: Encode<M> + Decode<R> {}
+
+impl<T, M, R> Codec<M, R> for T where T: Encode<M> + Decode<R> {}
+
+/// A transport-level failure: an I/O error, a handshake mismatch, or the
+/// stream closing with a request still in flight.
+#[derive(Display, Debug)]
+pub enum TransportError {
+    /// An I/O error occurred while reading from, or writing to, the stream.
+    #[display(fmt = "(transport) I/O Error: {}", _0)]
+    Io(io::Error),
+
+    /// The peer's handshake frame didn't match ours.
+    #[display(fmt = "(transport) Handshake Error: {}", _0)]
+    Handshake(String),
+
+    /// A frame declared a payload length larger than [`MAX_FRAME_LEN`].
+    #[display(fmt = "(transport) Frame Too Large Error: {} byte(s) (max {})", _0, _1)]
+    FrameTooLarge(u32, u32),
+
+    /// The stream closed while this request was still awaiting a response.
+    #[display(fmt = "(transport) Closed Error: connection closed with a request still in flight")]
+    Closed,
+}
+
+impl std::error::Error for TransportError {}
+
+impl PartialEq for TransportError {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Self::Io(err), Self::Io(other_err)) => {
+                err.kind() == other_err.kind()
+            },
+            (Self::Handshake(msg), Self::Handshake(other_msg)) => {
+                msg == other_msg
+            },
+            (
+                Self::FrameTooLarge(len, max),
+                Self::FrameTooLarge(other_len, other_max),
+            ) => len == other_len && max == other_max,
+            (Self::Closed, Self::Closed) => true,
+            _ => false,
+        }
+    }
+}
+
+/// Performs the versioned handshake at the start of a connection.
+///
+/// ### Notes:
+/// Both peers call this (one on each end of the stream) immediately after
+/// connecting and before any request/response frame is sent. If the
+/// peer's magic bytes or protocol version don't match ours, the handshake
+/// fails with [`TransportError::Handshake`] instead of letting the two
+/// sides desync on the framing of subsequent messages.
+pub async fn handshake<S>(stream: &mut S) -> Result<(), TransportError>
+where
+    S: AsyncRead + AsyncWrite + Unpin,
+{
+    stream
+        .write_all(&MAGIC)
+        .await
+        .map_err(TransportError::Io)?;
+    stream
+        .write_u8(PROTOCOL_VERSION)
+        .await
+        .map_err(TransportError::Io)?;
+
+    let mut peer_magic = [0u8; 4];
+    stream
+        .read_exact(&mut peer_magic)
+        .await
+        .map_err(TransportError::Io)?;
+    if peer_magic != MAGIC {
+        return Err(TransportError::Handshake(format!(
+            "expected magic bytes {MAGIC:?}, got {peer_magic:?}"
+        )));
+    }
+
+    let peer_version =
+        stream.read_u8().await.map_err(TransportError::Io)?;
+    if peer_version != PROTOCOL_VERSION {
+        return Err(TransportError::Handshake(format!(
+            "expected protocol version {PROTOCOL_VERSION}, got {peer_version}"
+        )));
+    }
+
+    Ok(())
+}
+
+/// Reads one `(request_id, payload)` frame off `stream`, rejecting a
+/// declared length greater than [`MAX_FRAME_LEN`] before allocating a
+/// buffer for it.
+async fn read_frame<S>(
+    stream: &mut S,
+) -> Result<(u64, Vec<u8>), TransportError>
+where
+    S: AsyncRead + Unpin,
+{
+    let id = stream.read_u64().await.map_err(TransportError::Io)?;
+    let len = stream.read_u32().await.map_err(TransportError::Io)?;
+    if len > MAX_FRAME_LEN {
+        return Err(TransportError::FrameTooLarge(len, MAX_FRAME_LEN));
+    }
+    let mut payload = vec![0u8; len as usize];
+    stream
+        .read_exact(&mut payload)
+        .await
+        .map_err(TransportError::Io)?;
+    Ok((id, payload))
+}
+
+/// Writes one `(request_id, payload)` frame to `stream`.
+async fn write_frame<S>(
+    stream: &mut S,
+    id: u64,
+    payload: &[u8],
+) -> io::Result<()>
+where
+    S: AsyncWrite + Unpin,
+{
+    stream.write_u64(id).await?;
+    stream.write_u32(payload.len() as u32).await?;
+    stream.write_all(payload).await?;
+    Ok(())
+}
+
+type Pending<M, R> =
+    Arc<Mutex<HashMap<u64, oneshot::Sender<Result<R, error::Error<M>>>>>>;
+
+/// Projects the [`crate::temporary::Sender::send_await_automatic`]
+/// ergonomics over a byte stream, so a [`Sender`] on one process can talk
+/// to a [`Receiver`] on another.
+///
+/// ### Notes:
+/// Every message is framed as `(request_id: u64, payload)`. A background
+/// task demultiplexes incoming `(request_id, payload)` frames and routes
+/// each to the caller awaiting that id via an internal map; unknown
+/// request ids are dropped, since they may belong to a request this
+/// connection no longer cares about.
+///
+/// If the stream closes, every outstanding call to [`Sender::send_await_automatic`]
+/// resolves to [`error::Error::Transport`] with [`TransportError::Closed`].
+// Not `#[derive(Debug)]` like the other `Sender` types: the shared codec
+// trait object has no meaningful `Debug` impl to forward to.
+pub struct Sender<M, R> {
+    write_tx: mpsc::Sender<(u64, Bytes)>,
+    pending: Pending<M, R>,
+    next_id: Arc<AtomicU64>,
+    codec: Arc<dyn Codec<M, R> + Send + Sync>,
+}
+
+impl<M, R> Sender<M, R>
+where
+    M: Send + 'static,
+    R: Send + 'static,
+{
+    /// Spawns the background reader/writer tasks for a handshaken stream
+    /// and returns a [`Sender`] for it.
+    ///
+    /// ### Arguments:
+    /// - `stream`: The already-handshaken (see [`handshake`]) byte stream.
+    /// - `codec`: The codec used to encode outgoing messages and decode
+    ///   incoming responses.
+    /// - `buffer`: The size of the internal, outgoing write queue.
+    pub fn new<S, C>(stream: S, codec: C, buffer: usize) -> Self
+    where
+        S: AsyncRead + AsyncWrite + Send + Unpin + 'static,
+        C: Codec<M, R> + Send + Sync + 'static,
+    {
+        let codec = Arc::new(codec);
+        let (mut read_half, mut write_half) = tokio::io::split(stream);
+        let (write_tx, mut write_rx) = mpsc::channel::<(u64, Bytes)>(buffer);
+        let pending: Pending<M, R> = Arc::new(Mutex::new(HashMap::new()));
+
+        tokio::task::spawn(async move {
+            while let Some((id, payload)) = write_rx.recv().await {
+                if write_frame(&mut write_half, id, &payload).await.is_err()
+                {
+                    break;
+                }
+            }
+        });
+
+        let reader_pending = Arc::clone(&pending);
+        let reader_codec = Arc::clone(&codec);
+        tokio::task::spawn(async move {
+            loop {
+                let (id, payload) = match read_frame(&mut read_half).await {
+                    Ok(frame) => frame,
+                    Err(_) => break,
+                };
+                let response = reader_codec.decode(&payload);
+                if let Some(response_tx) =
+                    reader_pending.lock().await.remove(&id)
+                {
+                    // the caller may have already given up (e.g., timed
+                    // out); dropping the response here is fine.
+                    let _ = response_tx.send(Ok(response));
+                }
+            }
+
+            for (_, response_tx) in reader_pending.lock().await.drain() {
+                let _ = response_tx.send(Err(error::Error::Transport(
+                    TransportError::Closed,
+                )));
+            }
+        });
+
+        Self {
+            write_tx,
+            pending,
+            next_id: Arc::new(AtomicU64::new(0)),
+            codec,
+        }
+    }
+
+    /// Sends the given message and awaits its response.
+    pub async fn send_await_automatic(
+        &self,
+        message: &M,
+    ) -> Result<R, error::Error<M>> {
+        let id = self.next_id.fetch_add(1, Ordering::Relaxed);
+        let payload = self.codec.encode(message);
+
+        let (response_tx, response_rx) = oneshot::channel();
+        self.pending.lock().await.insert(id, response_tx);
+
+        if self.write_tx.send((id, payload)).await.is_err() {
+            self.pending.lock().await.remove(&id);
+            return Err(error::Error::Transport(TransportError::Closed));
+        }
+
+        response_rx
+            .await
+            .unwrap_or(Err(error::Error::Transport(TransportError::Closed)))
+    }
+}
+
+/// The receiving half of a [`Sender`]'s transport, living on the peer
+/// process.
+///
+/// ### Notes:
+/// [`Receiver::recv`] yields the decoded message together with a
+/// [`ResponseHandle`] that, once [`ResponseHandle::respond`] is called,
+/// writes the encoded response back to the stream tagged with the
+/// originating request id, exactly like [`crate::temporary::channel`]'s
+/// `(message, reverse_tx)` pairs do for in-process channels.
+pub struct Receiver<M, R> {
+    read_rx: mpsc::Receiver<(u64, M)>,
+    write_tx: mpsc::Sender<(u64, Bytes)>,
+    codec: Arc<dyn Codec<R, M> + Send + Sync>,
+}
+
+impl<M, R> Receiver<M, R>
+where
+    M: Send + 'static,
+    R: Send + 'static,
+{
+    /// Spawns the background reader/writer tasks for a handshaken stream
+    /// and returns a [`Receiver`] for it.
+    ///
+    /// ### Arguments:
+    /// - `stream`: The already-handshaken (see [`handshake`]) byte stream.
+    /// - `codec`: The codec used to decode incoming messages and encode
+    ///   outgoing responses.
+    /// - `buffer`: The size of the internal queues feeding [`Receiver::recv`]
+    ///   and the outgoing write queue.
+    pub fn new<S, C>(stream: S, codec: C, buffer: usize) -> Self
+    where
+        S: AsyncRead + AsyncWrite + Send + Unpin + 'static,
+        C: Codec<R, M> + Send + Sync + 'static,
+    {
+        let codec = Arc::new(codec);
+        let (mut read_half, mut write_half) = tokio::io::split(stream);
+        let (write_tx, mut write_rx) = mpsc::channel::<(u64, Bytes)>(buffer);
+        let (read_tx, read_rx) = mpsc::channel::<(u64, M)>(buffer);
+
+        tokio::task::spawn(async move {
+            while let Some((id, payload)) = write_rx.recv().await {
+                if write_frame(&mut write_half, id, &payload).await.is_err()
+                {
+                    break;
+                }
+            }
+        });
+
+        let reader_codec = Arc::clone(&codec);
+        tokio::task::spawn(async move {
+            loop {
+                let (id, payload) = match read_frame(&mut read_half).await {
+                    Ok(frame) => frame,
+                    Err(_) => break,
+                };
+                let message = reader_codec.decode(&payload);
+                if read_tx.send((id, message)).await.is_err() {
+                    break;
+                }
+            }
+        });
+
+        Self {
+            read_rx,
+            write_tx,
+            codec,
+        }
+    }
+
+    /// Receives the next decoded message, together with a handle used to
+    /// send the response back to the [`Sender`] that sent it.
+    ///
+    /// Returns [`None`] once the stream has closed and no more messages
+    /// will ever arrive.
+    pub async fn recv(&mut self) -> Option<(M, ResponseHandle<M, R>)> {
+        let (id, message) = self.read_rx.recv().await?;
+        Some((
+            message,
+            ResponseHandle {
+                id,
+                write_tx: self.write_tx.clone(),
+                codec: Arc::clone(&self.codec),
+                _marker: std::marker::PhantomData,
+            },
+        ))
+    }
+}
+
+/// A handle that sends a single response back over a [`Receiver`]'s
+/// transport, tagged with the request id it answers.
+pub struct ResponseHandle<M, R> {
+    id: u64,
+    write_tx: mpsc::Sender<(u64, Bytes)>,
+    codec: Arc<dyn Codec<R, M> + Send + Sync>,
+    _marker: std::marker::PhantomData<M>,
+}
+
+impl<M, R> ResponseHandle<M, R> {
+    /// Encodes and sends `response` back to the peer that sent the
+    /// request this handle answers.
+    pub async fn respond(self, response: R) -> Result<(), TransportError> {
+        let payload = self.codec.encode(&response);
+        self.write_tx
+            .send((self.id, payload))
+            .await
+            .map_err(|_| TransportError::Closed)
+    }
+}